@@ -0,0 +1,545 @@
+use alloy::{json_abi::Constructor, primitives::Address};
+use async_trait::async_trait;
+use std::fmt;
+
+/// A source of contract verification metadata used to populate deployment logs. Chains don't
+/// all expose the same verification API, so `RegisterContractWorkflow` resolves one of these
+/// per chain id (or an explicit user choice) instead of hard-coding a single explorer.
+#[async_trait]
+pub trait ExplorerBackend: Send + Sync {
+    /// Returns the contract's name, its raw ABI-encoded constructor arguments, and the decoded
+    /// `Constructor` ABI entry (if the contract declares one).
+    async fn get_contract_data(
+        &self,
+        contract_address: Address,
+    ) -> Result<(String, String, Option<Constructor>), Box<dyn std::error::Error>>;
+
+    async fn get_creation_transaction_hash(
+        &self,
+        contract_address: Address,
+    ) -> Result<String, Box<dyn std::error::Error>>;
+
+    /// Submits standard-JSON-input source verification for an already-deployed contract and
+    /// returns a GUID that `check_verification_status` can be polled with. Backends that verify
+    /// synchronously may return a placeholder GUID; the default implementation, for backends that
+    /// can't verify sources, returns `VerificationUnsupportedError`.
+    async fn submit_verification(
+        &self,
+        _contract_address: Address,
+        _contract_name: String,
+        _standard_json_input: serde_json::Value,
+        _compiler_version: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Err(VerificationUnsupportedError.into())
+    }
+
+    async fn check_verification_status(
+        &self,
+        _guid: String,
+        _contract_address: Address,
+    ) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+        Err(VerificationUnsupportedError.into())
+    }
+}
+
+/// The outcome of polling a backend's verification GUID.
+pub enum VerificationStatus {
+    Pending,
+    Success { explorer_url: String },
+    Failed(String),
+}
+
+/// Returned by a backend when it has no record of the contract being verified, so that
+/// `MultiExplorerBackend` knows to try the next one instead of surfacing the error.
+#[derive(Debug)]
+pub struct NotVerifiedError;
+
+impl fmt::Display for NotVerifiedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "contract is not verified on this backend")
+    }
+}
+
+impl std::error::Error for NotVerifiedError {}
+
+/// Returned by a backend's default `submit_verification`/`check_verification_status` when it
+/// doesn't support source verification at all.
+#[derive(Debug)]
+pub struct VerificationUnsupportedError;
+
+impl fmt::Display for VerificationUnsupportedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "this backend does not support source verification")
+    }
+}
+
+impl std::error::Error for VerificationUnsupportedError {}
+
+/// An Etherscan-compatible API (Etherscan, Polygonscan, Arbiscan, ...), selected by the
+/// explorer's base URL and API key.
+pub struct EtherscanBackend {
+    pub api_url: String,
+    pub api_key: String,
+}
+
+#[async_trait]
+impl ExplorerBackend for EtherscanBackend {
+    async fn get_contract_data(
+        &self,
+        contract_address: Address,
+    ) -> Result<(String, String, Option<Constructor>), Box<dyn std::error::Error>> {
+        let response: serde_json::Value = reqwest::get(format!(
+            "{}?module=contract&action=getsourcecode&address={}&apikey={}",
+            self.api_url, contract_address, self.api_key
+        ))
+        .await?
+        .json()
+        .await?;
+
+        let result = &response["result"][0];
+        let contract_name = result["ContractName"]
+            .as_str()
+            .filter(|name| !name.is_empty())
+            .ok_or(NotVerifiedError)?
+            .to_string();
+        let constructor_arguments = result["ConstructorArguments"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+        let abi: Option<Vec<serde_json::Value>> =
+            serde_json::from_str(result["ABI"].as_str().unwrap_or("")).ok();
+        let constructor = abi.and_then(|abi| {
+            abi.into_iter()
+                .find(|entry| entry["type"] == "constructor")
+                .and_then(|entry| serde_json::from_value(entry).ok())
+        });
+
+        Ok((contract_name, constructor_arguments, constructor))
+    }
+
+    async fn get_creation_transaction_hash(
+        &self,
+        contract_address: Address,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let response: serde_json::Value = reqwest::get(format!(
+            "{}?module=contract&action=getcontractcreation&contractaddresses={}&apikey={}",
+            self.api_url, contract_address, self.api_key
+        ))
+        .await?
+        .json()
+        .await?;
+
+        response["result"][0]["txHash"]
+            .as_str()
+            .map(|hash| hash.to_string())
+            .ok_or_else(|| NotVerifiedError.into())
+    }
+
+    async fn submit_verification(
+        &self,
+        contract_address: Address,
+        contract_name: String,
+        standard_json_input: serde_json::Value,
+        compiler_version: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        etherscan_compatible_submit_verification(
+            &self.api_url,
+            Some(&self.api_key),
+            contract_address,
+            contract_name,
+            standard_json_input,
+            compiler_version,
+        )
+        .await
+    }
+
+    async fn check_verification_status(
+        &self,
+        guid: String,
+        contract_address: Address,
+    ) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+        etherscan_compatible_check_verification_status(
+            &self.api_url,
+            Some(&self.api_key),
+            guid,
+            contract_address,
+        )
+        .await
+    }
+}
+
+/// Blockscout exposes an Etherscan-compatible `api` endpoint on most deployments, but doesn't
+/// require an API key and reports unverified contracts with an empty `ABI` field rather than
+/// an error status.
+pub struct BlockscoutBackend {
+    pub api_url: String,
+}
+
+#[async_trait]
+impl ExplorerBackend for BlockscoutBackend {
+    async fn get_contract_data(
+        &self,
+        contract_address: Address,
+    ) -> Result<(String, String, Option<Constructor>), Box<dyn std::error::Error>> {
+        let response: serde_json::Value = reqwest::get(format!(
+            "{}?module=contract&action=getsourcecode&address={}",
+            self.api_url, contract_address
+        ))
+        .await?
+        .json()
+        .await?;
+
+        let result = &response["result"][0];
+        let contract_name = result["ContractName"]
+            .as_str()
+            .filter(|name| !name.is_empty())
+            .ok_or(NotVerifiedError)?
+            .to_string();
+        let constructor_arguments = result["ConstructorArguments"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+        let abi: Option<Vec<serde_json::Value>> =
+            serde_json::from_str(result["ABI"].as_str().unwrap_or("")).ok();
+        let constructor = abi.and_then(|abi| {
+            abi.into_iter()
+                .find(|entry| entry["type"] == "constructor")
+                .and_then(|entry| serde_json::from_value(entry).ok())
+        });
+
+        Ok((contract_name, constructor_arguments, constructor))
+    }
+
+    async fn get_creation_transaction_hash(
+        &self,
+        contract_address: Address,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let response: serde_json::Value = reqwest::get(format!(
+            "{}?module=contract&action=getcontractcreation&contractaddresses={}",
+            self.api_url, contract_address
+        ))
+        .await?
+        .json()
+        .await?;
+
+        response["result"][0]["txHash"]
+            .as_str()
+            .map(|hash| hash.to_string())
+            .ok_or_else(|| NotVerifiedError.into())
+    }
+
+    async fn submit_verification(
+        &self,
+        contract_address: Address,
+        contract_name: String,
+        standard_json_input: serde_json::Value,
+        compiler_version: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        etherscan_compatible_submit_verification(
+            &self.api_url,
+            None,
+            contract_address,
+            contract_name,
+            standard_json_input,
+            compiler_version,
+        )
+        .await
+    }
+
+    async fn check_verification_status(
+        &self,
+        guid: String,
+        contract_address: Address,
+    ) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+        etherscan_compatible_check_verification_status(&self.api_url, None, guid, contract_address)
+            .await
+    }
+}
+
+/// Shared by `EtherscanBackend` and `BlockscoutBackend`, which both speak the same
+/// `verifysourcecode`/`checkverifystatus` API - only the presence of an API key differs.
+async fn etherscan_compatible_submit_verification(
+    api_url: &str,
+    api_key: Option<&str>,
+    contract_address: Address,
+    contract_name: String,
+    standard_json_input: serde_json::Value,
+    compiler_version: String,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut form = vec![
+        ("module".to_string(), "contract".to_string()),
+        ("action".to_string(), "verifysourcecode".to_string()),
+        ("contractaddress".to_string(), contract_address.to_string()),
+        ("contractname".to_string(), contract_name),
+        (
+            "codeformat".to_string(),
+            "solidity-standard-json-input".to_string(),
+        ),
+        ("compilerversion".to_string(), compiler_version),
+        (
+            "sourceCode".to_string(),
+            serde_json::to_string(&standard_json_input)?,
+        ),
+    ];
+    if let Some(api_key) = api_key {
+        form.push(("apikey".to_string(), api_key.to_string()));
+    }
+
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(api_url)
+        .form(&form)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    if response["status"] != "1" {
+        return Err(format!(
+            "Verification submission failed: {}",
+            response["result"].as_str().unwrap_or("unknown error")
+        )
+        .into());
+    }
+    Ok(response["result"].as_str().unwrap_or_default().to_string())
+}
+
+async fn etherscan_compatible_check_verification_status(
+    api_url: &str,
+    api_key: Option<&str>,
+    guid: String,
+    contract_address: Address,
+) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+    let mut url = format!(
+        "{}?module=contract&action=checkverifystatus&guid={}",
+        api_url, guid
+    );
+    if let Some(api_key) = api_key {
+        url.push_str(&format!("&apikey={}", api_key));
+    }
+
+    let response: serde_json::Value = reqwest::get(url).await?.json().await?;
+    let result = response["result"].as_str().unwrap_or_default();
+
+    if result.contains("Pending") {
+        Ok(VerificationStatus::Pending)
+    } else if result.contains("Fail") {
+        Ok(VerificationStatus::Failed(result.to_string()))
+    } else if response["status"] == "1" {
+        Ok(VerificationStatus::Success {
+            explorer_url: format!(
+                "{}/address/{}#code",
+                explorer_site_base(api_url),
+                contract_address
+            ),
+        })
+    } else {
+        Ok(VerificationStatus::Failed(result.to_string()))
+    }
+}
+
+/// Etherscan-compatible backends are configured with their API endpoint (e.g.
+/// `https://api.etherscan.io/api`), not the human-facing site - derive the latter by dropping
+/// the `api.` subdomain prefix and the trailing `/api` path so verification results can link to
+/// an actual contract page instead of the API root.
+fn explorer_site_base(api_url: &str) -> String {
+    api_url
+        .trim_end_matches('/')
+        .trim_end_matches("/api")
+        .replacen("://api.", "://", 1)
+        .to_string()
+}
+
+/// Sourcify matches contracts by bytecode rather than by address lookup against a single API
+/// key, and only publishes source + metadata - the creation transaction hash has to come from
+/// elsewhere, so of the two lookups this backend only answers `get_contract_data`.
+pub struct SourcifyBackend {
+    pub api_url: String,
+    pub chain_id: String,
+}
+
+#[async_trait]
+impl ExplorerBackend for SourcifyBackend {
+    async fn get_contract_data(
+        &self,
+        contract_address: Address,
+    ) -> Result<(String, String, Option<Constructor>), Box<dyn std::error::Error>> {
+        let response = reqwest::get(format!(
+            "{}/files/any/{}/{}",
+            self.api_url, self.chain_id, contract_address
+        ))
+        .await?;
+        if !response.status().is_success() {
+            return Err(NotVerifiedError.into());
+        }
+        let body: serde_json::Value = response.json().await?;
+
+        let metadata: serde_json::Value = body["files"]
+            .as_array()
+            .and_then(|files| files.iter().find(|file| file["name"] == "metadata.json"))
+            .and_then(|file| serde_json::from_str(file["content"].as_str()?).ok())
+            .ok_or(NotVerifiedError)?;
+
+        let contract_name = metadata["output"]["abi"]
+            .as_array()
+            .and_then(|_| {
+                metadata["settings"]["compilationTarget"]
+                    .as_object()
+                    .and_then(|targets| targets.values().next())
+            })
+            .and_then(|name| name.as_str())
+            .ok_or(NotVerifiedError)?
+            .to_string();
+
+        // Sourcify doesn't return ABI-encoded constructor arguments alongside the metadata, so
+        // there's nothing to decode - reporting no constructor (rather than the real one paired
+        // with an empty argument string) tells the caller to record an empty `input.constructor`
+        // instead of decoding empty bytes against an ABI that expects arguments.
+        Ok((contract_name, String::new(), None))
+    }
+
+    async fn get_creation_transaction_hash(
+        &self,
+        _contract_address: Address,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Err("Sourcify does not expose a creation transaction hash".into())
+    }
+
+    async fn submit_verification(
+        &self,
+        contract_address: Address,
+        _contract_name: String,
+        standard_json_input: serde_json::Value,
+        _compiler_version: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        // Sourcify verifies synchronously against the request body, so the "GUID" it hands back
+        // is just the address check_verification_status needs to report success for.
+        reqwest::Client::new()
+            .post(format!("{}/verify/solc-json", self.api_url))
+            .json(&serde_json::json!({
+                "address": contract_address,
+                "chain": self.chain_id,
+                "files": { "input.json": standard_json_input },
+            }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(contract_address.to_string())
+    }
+
+    async fn check_verification_status(
+        &self,
+        guid: String,
+        _contract_address: Address,
+    ) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+        Ok(VerificationStatus::Success {
+            explorer_url: format!(
+                "{}/contracts/full_match/{}/{}",
+                self.api_url, self.chain_id, guid
+            ),
+        })
+    }
+}
+
+/// Tries each backend in order and returns the first one that resolves the contract, so a
+/// chain without an Etherscan-compatible explorer still falls through to Blockscout/Sourcify.
+/// Only a `NotVerifiedError` moves on to the next backend; any other error is returned at once.
+pub struct MultiExplorerBackend {
+    backends: Vec<Box<dyn ExplorerBackend>>,
+}
+
+impl MultiExplorerBackend {
+    pub fn new(backends: Vec<Box<dyn ExplorerBackend>>) -> Self {
+        MultiExplorerBackend { backends }
+    }
+}
+
+#[async_trait]
+impl ExplorerBackend for MultiExplorerBackend {
+    async fn get_contract_data(
+        &self,
+        contract_address: Address,
+    ) -> Result<(String, String, Option<Constructor>), Box<dyn std::error::Error>> {
+        let mut last_error: Option<Box<dyn std::error::Error>> = None;
+        for backend in &self.backends {
+            match backend.get_contract_data(contract_address).await {
+                Ok(data) => return Ok(data),
+                Err(error) if error.downcast_ref::<NotVerifiedError>().is_some() => {
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| NotVerifiedError.into()))
+    }
+
+    async fn get_creation_transaction_hash(
+        &self,
+        contract_address: Address,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut last_error: Option<Box<dyn std::error::Error>> = None;
+        for backend in &self.backends {
+            match backend
+                .get_creation_transaction_hash(contract_address)
+                .await
+            {
+                Ok(hash) => return Ok(hash),
+                Err(error) if error.downcast_ref::<NotVerifiedError>().is_some() => {
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| NotVerifiedError.into()))
+    }
+
+    async fn submit_verification(
+        &self,
+        contract_address: Address,
+        contract_name: String,
+        standard_json_input: serde_json::Value,
+        compiler_version: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut last_error: Option<Box<dyn std::error::Error>> = None;
+        for (index, backend) in self.backends.iter().enumerate() {
+            match backend
+                .submit_verification(
+                    contract_address,
+                    contract_name.clone(),
+                    standard_json_input.clone(),
+                    compiler_version.clone(),
+                )
+                .await
+            {
+                // The backend that accepted the submission has to be the one polled for status,
+                // so its index travels along with the GUID.
+                Ok(guid) => return Ok(format!("{}:{}", index, guid)),
+                Err(error)
+                    if error
+                        .downcast_ref::<VerificationUnsupportedError>()
+                        .is_some() =>
+                {
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| VerificationUnsupportedError.into()))
+    }
+
+    async fn check_verification_status(
+        &self,
+        guid: String,
+        contract_address: Address,
+    ) -> Result<VerificationStatus, Box<dyn std::error::Error>> {
+        let (index, guid) = guid
+            .split_once(':')
+            .ok_or("Malformed multi-backend verification GUID")?;
+        let backend = self
+            .backends
+            .get(index.parse::<usize>()?)
+            .ok_or("Unknown backend index in verification GUID")?;
+        backend
+            .check_verification_status(guid.to_string(), contract_address)
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+use alloy::primitives::{Address, FixedBytes};
+use async_trait::async_trait;
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A deployment worth telling the outside world about, emitted once `generate_deployment_log`
+/// has successfully written the JSON log and regenerated the forge-chronicles changelog.
+pub struct DeploymentNotice {
+    pub contract_name: String,
+    pub contract_address: Address,
+    pub chain_id: String,
+    pub deployment_tx_hash: String,
+    pub pool_init_code_hash: Option<FixedBytes<32>>,
+}
+
+/// A sink that a successful deployment gets reported to. Implement this to add a new
+/// notification channel without touching the deployment-logging flow itself.
+#[async_trait]
+pub trait DeploymentNotifier: Send + Sync {
+    async fn notify(&self, notice: &DeploymentNotice) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Posts a structured JSON payload to a generic HTTP webhook URL.
+pub struct WebhookNotifier {
+    pub url: String,
+}
+
+#[async_trait]
+impl DeploymentNotifier for WebhookNotifier {
+    async fn notify(&self, notice: &DeploymentNotice) -> Result<(), Box<dyn std::error::Error>> {
+        reqwest::Client::new()
+            .post(&self.url)
+            .json(&json!({
+                "contractName": notice.contract_name,
+                "contractAddress": notice.contract_address,
+                "chainId": notice.chain_id,
+                "deploymentTxnHash": notice.deployment_tx_hash,
+                "poolInitCodeHash": notice.pool_init_code_hash.map(|hash| hash.to_string()),
+            }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Posts a plain-text message into a Matrix room via the client-server API.
+pub struct MatrixNotifier {
+    pub homeserver_url: String,
+    pub access_token: String,
+    pub room_id: String,
+}
+
+#[async_trait]
+impl DeploymentNotifier for MatrixNotifier {
+    async fn notify(&self, notice: &DeploymentNotice) -> Result<(), Box<dyn std::error::Error>> {
+        let mut body = format!(
+            "Deployed {} on chain {} at {} (tx {})",
+            notice.contract_name,
+            notice.chain_id,
+            notice.contract_address,
+            notice.deployment_tx_hash
+        );
+        if let Some(pool_init_code_hash) = notice.pool_init_code_hash {
+            body.push_str(&format!(", poolInitCodeHash {}", pool_init_code_hash));
+        }
+
+        // The Matrix send-message endpoint is keyed by a client-chosen transaction id for
+        // idempotency; a nanosecond timestamp is unique enough for a one-shot notification.
+        let txn_id = SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .as_nanos()
+            .to_string();
+
+        reqwest::Client::new()
+            .put(format!(
+                "{}/_matrix/client/v3/rooms/{}/send/m.room.message/{}",
+                self.homeserver_url, self.room_id, txn_id
+            ))
+            .bearer_auth(&self.access_token)
+            .json(&json!({
+                "msgtype": "m.text",
+                "body": body,
+            }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::libs::explorer::ExplorerApiLib;
+use crate::libs::explorer_backend::ExplorerBackend;
+use crate::libs::notifier::{DeploymentNotice, DeploymentNotifier};
 use crate::libs::web3::Web3Lib;
 use alloy::{
     dyn_abi::{DynSolValue, JsonAbiExt},
@@ -7,6 +8,7 @@ use alloy::{
     json_abi::Param,
     primitives::{Address, FixedBytes},
 };
+use serde::Deserialize;
 use serde_json::json;
 use std::path::PathBuf;
 
@@ -14,25 +16,11 @@ pub async fn generate_deployment_log(
     contract_address: Address,
     chain_id: String,
     working_dir: PathBuf,
-    explorer_api: ExplorerApiLib,
+    explorer_api: Box<dyn ExplorerBackend>,
     mut web3: Web3Lib,
+    notifiers: Vec<Box<dyn DeploymentNotifier>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // check if deployments/chainid.json exists, if yes load it
-    let deployments_file = working_dir
-        .join("deployments")
-        .join("json")
-        .join(format!("{}.json", chain_id));
-    let mut deployments_json = if deployments_file.exists() {
-        let contents = std::fs::read_to_string(&deployments_file)
-            .expect("Could not read deployments json file");
-        serde_json::from_str(&contents).expect("Deployments JSON was not well-formatted")
-    } else {
-        serde_json::json!({
-            "chainId": chain_id,
-            "latest": {},
-            "history": []
-        })
-    };
+    let mut deployments_json = load_deployments_json(&working_dir, &chain_id)?;
 
     let (contract_name, constructor_arguments, constructor) =
         explorer_api.get_contract_data(contract_address).await?;
@@ -67,6 +55,12 @@ pub async fn generate_deployment_log(
         .get_block_timestamp_by_block_number(block_number)
         .await?;
 
+    let proxy_info = detect_proxy(&mut web3, contract_address, block_number).await?;
+
+    // `constructor_arguments` was ABI-encoded at `contract_address`'s own creation, so it has to
+    // be decoded against that same contract's constructor - a proxy's creation tx only ever
+    // carries the proxy's own constructor args (e.g. `(address,address,bytes)` for a
+    // TransparentUpgradeableProxy), never the implementation's.
     let mut args = None;
     if constructor.is_some() {
         args = Some(
@@ -94,14 +88,23 @@ pub async fn generate_deployment_log(
         "contracts": {
             contract_name.clone(): {
                 "address": contract_address,
-                // TODO: add proxy support
-                "proxy": false,
+                "proxy": proxy_info.is_some(),
                 "deploymentTxn": tx_hash,
                 "input": {}
             }
         },
         "timestamp": timestamp
     });
+    if let Some(proxy) = &proxy_info {
+        contract_data["contracts"][contract_name.clone()]["implementation"] =
+            proxy.implementation.to_string().into();
+        if let Some(admin) = proxy.admin {
+            contract_data["contracts"][contract_name.clone()]["admin"] = admin.to_string().into();
+        }
+        if let Some(beacon) = proxy.beacon {
+            contract_data["contracts"][contract_name.clone()]["beacon"] = beacon.to_string().into();
+        }
+    }
     if args.is_some() {
         contract_data["contracts"][contract_name.clone()]["input"]["constructor"] =
             extract_constructor_inputs(constructor.unwrap().inputs, args.unwrap())?;
@@ -109,7 +112,7 @@ pub async fn generate_deployment_log(
         contract_data["contracts"][contract_name.clone()]["input"]["constructor"] = json!({});
     }
     if pool_init_code_hash.is_some() {
-        contract_data["contracts"][contract_name]["poolInitCodeHash"] =
+        contract_data["contracts"][contract_name.clone()]["poolInitCodeHash"] =
             pool_init_code_hash.unwrap().to_string().into();
     }
     deployments_json["history"]
@@ -117,13 +120,271 @@ pub async fn generate_deployment_log(
         .unwrap()
         .push(contract_data);
 
+    write_deployments_json(&working_dir, &chain_id, deployments_json)?;
+    run_forge_chronicles(&working_dir, &chain_id)?;
+
+    notify_deployment(
+        &notifiers,
+        DeploymentNotice {
+            contract_name,
+            contract_address,
+            chain_id,
+            deployment_tx_hash: tx_hash,
+            pool_init_code_hash,
+        },
+    )
+    .await;
+    Ok(())
+}
+
+/// Reports a successful deployment to every configured sink. A notifier failing (a webhook
+/// being unreachable, say) shouldn't fail the deployment-logging run that already succeeded,
+/// so errors are logged rather than propagated.
+async fn notify_deployment(notifiers: &[Box<dyn DeploymentNotifier>], notice: DeploymentNotice) {
+    for notifier in notifiers {
+        if let Err(error) = notifier.notify(&notice).await {
+            eprintln!("Failed to send deployment notification: {}", error);
+        }
+    }
+}
+
+/// EIP-1967 storage slot holding the implementation address
+/// (`bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`).
+const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+/// EIP-1967 storage slot holding the proxy admin address.
+const EIP1967_ADMIN_SLOT: &str = "b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103";
+/// EIP-1967 storage slot holding the beacon address (for beacon proxies).
+const EIP1967_BEACON_SLOT: &str =
+    "a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50";
+
+struct ProxyInfo {
+    implementation: Address,
+    admin: Option<Address>,
+    beacon: Option<Address>,
+}
+
+/// Reads the well-known EIP-1967 storage slots at `contract_address` as of `block_number` to
+/// determine whether the contract is a proxy, and if so, what it points at. Only a non-zero
+/// implementation slot marks a proxy; the admin and beacon slots are read only in that case, so
+/// a beacon proxy that leaves the implementation slot empty is reported as not a proxy.
+async fn detect_proxy(
+    web3: &mut Web3Lib,
+    contract_address: Address,
+    block_number: u64,
+) -> Result<Option<ProxyInfo>, Box<dyn std::error::Error>> {
+    let implementation = address_from_slot(
+        web3.get_storage_at(
+            contract_address,
+            FixedBytes::<32>::from_hex(EIP1967_IMPLEMENTATION_SLOT)?,
+            block_number,
+        )
+        .await?,
+    );
+    let Some(implementation) = implementation else {
+        return Ok(None);
+    };
+
+    let admin = address_from_slot(
+        web3.get_storage_at(
+            contract_address,
+            FixedBytes::<32>::from_hex(EIP1967_ADMIN_SLOT)?,
+            block_number,
+        )
+        .await?,
+    );
+    let beacon = address_from_slot(
+        web3.get_storage_at(
+            contract_address,
+            FixedBytes::<32>::from_hex(EIP1967_BEACON_SLOT)?,
+            block_number,
+        )
+        .await?,
+    );
+
+    Ok(Some(ProxyInfo {
+        implementation,
+        admin,
+        beacon,
+    }))
+}
+
+fn address_from_slot(slot: FixedBytes<32>) -> Option<Address> {
+    if slot.is_zero() {
+        return None;
+    }
+    Some(Address::from_slice(&slot[12..]))
+}
+
+/// Ingests a Foundry `broadcast/<script>/<chainId>/run-latest.json` file and batch-registers
+/// every `CREATE`/`CREATE2` transaction it contains, without querying an explorer for each
+/// address individually. This is handy for multi-contract deployments and for chains where
+/// verification hasn't happened (yet).
+pub async fn generate_deployment_log_from_broadcast(
+    script_name: String,
+    chain_id: String,
+    working_dir: PathBuf,
+    mut web3: Web3Lib,
+    notifiers: Vec<Box<dyn DeploymentNotifier>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut deployments_json = load_deployments_json(&working_dir, &chain_id)?;
+    let mut notices = Vec::new();
+
+    let broadcast_file = working_dir
+        .join("broadcast")
+        .join(&script_name)
+        .join(&chain_id)
+        .join("run-latest.json");
+    if !broadcast_file.exists() {
+        return Err(format!(
+            "Broadcast file not found at '{}'. Run 'forge script {} --broadcast' first",
+            broadcast_file.display(),
+            script_name
+        )
+        .into());
+    }
+    let broadcast: BroadcastFile = serde_json::from_str(
+        &std::fs::read_to_string(&broadcast_file).expect("Could not read broadcast file"),
+    )
+    .expect("Broadcast JSON was not well-formatted");
+
+    for transaction in &broadcast.transactions {
+        if transaction.transaction_type != "CREATE" && transaction.transaction_type != "CREATE2" {
+            continue;
+        }
+        let (Some(contract_name), Some(contract_address)) =
+            (&transaction.contract_name, transaction.contract_address)
+        else {
+            continue;
+        };
+
+        let local_out_path = working_dir
+            .join("out")
+            .join(contract_name.to_string() + ".sol")
+            .join(contract_name.to_string() + ".json");
+        if !local_out_path.exists() {
+            return Err(format!(
+                "Smart contract '{}' not found in foundry 'out' directory",
+                contract_name
+            )
+            .into());
+        }
+
+        detect_duplicate(
+            deployments_json["history"].as_array().unwrap().clone(),
+            contract_address.to_string(),
+        )?;
+
+        let block_number = web3
+            .get_block_number_by_transaction_hash(FixedBytes::<32>::from_hex(
+                transaction.hash.as_str(),
+            )?)
+            .await?;
+
+        let timestamp = web3
+            .get_block_timestamp_by_block_number(block_number)
+            .await?;
+
+        let mut pool_init_code_hash: Option<FixedBytes<32>> = None;
+        if contract_name == "UniswapV2Factory" {
+            pool_init_code_hash = Some(
+                web3.get_v2_pool_init_code_hash(contract_address, block_number)
+                    .await?,
+            );
+        } else if contract_name == "UniswapV3Factory" {
+            pool_init_code_hash = Some(
+                web3.get_v3_pool_init_code_hash(contract_address, block_number)
+                    .await?,
+            );
+        }
+
+        let mut contract_data = json!({
+            "contracts": {
+                contract_name.clone(): {
+                    "address": contract_address,
+                    "proxy": false,
+                    "deploymentTxn": transaction.hash,
+                    "input": {
+                        "constructor": extract_constructor_inputs_from_broadcast(
+                            &local_out_path,
+                            transaction.arguments.as_deref().unwrap_or(&[]),
+                        )?
+                    }
+                }
+            },
+            "timestamp": timestamp
+        });
+        if let Some(pool_init_code_hash) = pool_init_code_hash {
+            contract_data["contracts"][contract_name.clone()]["poolInitCodeHash"] =
+                pool_init_code_hash.to_string().into();
+        }
+        deployments_json["history"]
+            .as_array_mut()
+            .unwrap()
+            .push(contract_data);
+
+        notices.push(DeploymentNotice {
+            contract_name: contract_name.clone(),
+            contract_address,
+            chain_id: chain_id.clone(),
+            deployment_tx_hash: transaction.hash.clone(),
+            pool_init_code_hash,
+        });
+    }
+
+    write_deployments_json(&working_dir, &chain_id, deployments_json)?;
+    run_forge_chronicles(&working_dir, &chain_id)?;
+
+    for notice in notices {
+        notify_deployment(&notifiers, notice).await;
+    }
+    Ok(())
+}
+
+pub(crate) fn load_deployments_json(
+    working_dir: &PathBuf,
+    chain_id: &str,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let deployments_file = deployments_file_path(working_dir, chain_id);
+    Ok(if deployments_file.exists() {
+        let contents = std::fs::read_to_string(&deployments_file)
+            .expect("Could not read deployments json file");
+        serde_json::from_str(&contents).expect("Deployments JSON was not well-formatted")
+    } else {
+        serde_json::json!({
+            "chainId": chain_id,
+            "latest": {},
+            "history": []
+        })
+    })
+}
+
+pub(crate) fn write_deployments_json(
+    working_dir: &PathBuf,
+    chain_id: &str,
+    deployments_json: serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let deployments_file = deployments_file_path(working_dir, chain_id);
     std::fs::create_dir_all(deployments_file.parent().unwrap())?;
-    deployments_json = post_process_deployments_json(deployments_json)?;
+    let deployments_json = post_process_deployments_json(deployments_json)?;
     std::fs::write(
         deployments_file,
         serde_json::to_string_pretty(&deployments_json).unwrap(),
     )?;
+    Ok(())
+}
 
+pub(crate) fn deployments_file_path(working_dir: &PathBuf, chain_id: &str) -> PathBuf {
+    working_dir
+        .join("deployments")
+        .join("json")
+        .join(format!("{}.json", chain_id))
+}
+
+fn run_forge_chronicles(
+    working_dir: &PathBuf,
+    chain_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
     let forge_chronicles_path = working_dir
         .join("lib")
         .join("forge-chronicles")
@@ -146,11 +407,68 @@ pub async fn generate_deployment_log(
     Ok(())
 }
 
+#[derive(Deserialize)]
+struct BroadcastFile {
+    transactions: Vec<BroadcastTransaction>,
+}
+
+#[derive(Deserialize)]
+struct BroadcastTransaction {
+    #[serde(rename = "contractName")]
+    contract_name: Option<String>,
+    #[serde(rename = "contractAddress")]
+    contract_address: Option<Address>,
+    #[serde(rename = "transactionType")]
+    transaction_type: String,
+    arguments: Option<Vec<String>>,
+    hash: String,
+}
+
+/// Broadcast artifacts carry constructor arguments as already-formatted strings rather than
+/// ABI-encoded bytes, so there's no decoding to do here - each argument just needs lining up
+/// with its parameter name, read from the local `out/<name>.sol/<name>.json` artifact's ABI, to
+/// match the named-key shape `extract_constructor_inputs` produces for the explorer path.
+fn extract_constructor_inputs_from_broadcast(
+    artifact_path: &PathBuf,
+    arguments: &[String],
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let param_names = constructor_param_names(artifact_path)?;
+    let mut result = json!({});
+    for (i, argument) in arguments.iter().enumerate() {
+        let key = param_names.get(i).cloned().unwrap_or_else(|| i.to_string());
+        result[key] = json!(argument);
+    }
+    Ok(result)
+}
+
+/// Reads a Foundry artifact's ABI and returns its constructor's parameter names in declaration
+/// order, or an empty list if the contract declares no constructor.
+fn constructor_param_names(
+    artifact_path: &PathBuf,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let artifact: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(artifact_path)?)?;
+    let names = artifact["abi"]
+        .as_array()
+        .and_then(|abi| abi.iter().find(|entry| entry["type"] == "constructor"))
+        .and_then(|entry| entry["inputs"].as_array())
+        .map(|inputs| {
+            inputs
+                .iter()
+                .map(|input| input["name"].as_str().unwrap_or_default().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(names)
+}
+
 fn detect_duplicate(
     deployments_history: Vec<serde_json::Value>,
     address: String,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // TODO: add proxy support
+    // Only the deployed contract's own address counts as a duplicate - a proxy and its
+    // implementation inevitably live at different addresses and must both be loggable, as
+    // can the same implementation being pointed at by several proxies.
     let history = deployments_history.clone();
     for item in history {
         for (_, v) in item["contracts"].as_object().unwrap() {
@@ -191,6 +509,13 @@ fn post_process_deployments_json(
             if !v["poolInitCodeHash"].is_null() {
                 latest[k]["poolInitCodeHash"] = v["poolInitCodeHash"].clone();
             }
+            if !v["implementation"].is_null() {
+                latest[k]["implementation"] = v["implementation"].clone();
+            }
+            if !v["verified"].is_null() {
+                latest[k]["verified"] = v["verified"].clone();
+                latest[k]["explorerUrl"] = v["explorerUrl"].clone();
+            }
         }
     }
     deployments_json["history"] = serde_json::Value::Array(history);
@@ -226,17 +551,116 @@ fn serialize_value(
         DynSolValue::Tuple(t) => extract_constructor_inputs(param.components, t),
         DynSolValue::Function(f) => Ok(f.to_string().into()),
         DynSolValue::Bytes(b) => Ok(hex::encode(DynSolValue::Bytes(b).abi_encode()).into()),
-        DynSolValue::Array(_) => {
-            // TODO
-            // let mut arr = json!([]);
-            // push items into arr
-            Err("Found array, not implemented".into())
-        }
-        DynSolValue::FixedArray(_) => {
-            // TODO
-            // let mut arr = json!([]);
-            // push items into arr
-            Err("Found fixed array, not implemented".into())
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            let element_param = array_element_param(&param);
+            let mut serialized = Vec::with_capacity(values.len());
+            for value in values {
+                serialized.push(serialize_value(element_param.clone(), value)?);
+            }
+            Ok(serde_json::Value::Array(serialized))
         }
     };
-}
\ No newline at end of file
+}
+
+/// Derives the `Param` describing a single element of an array/fixed-array type, by stripping
+/// the outermost `[]`/`[N]` suffix from `param.ty`. `components` carries over unchanged, since
+/// that's where a `tuple[]`/`tuple[N]` element's field names and types already live.
+fn array_element_param(param: &Param) -> Param {
+    let element_ty = match param.ty.rfind('[') {
+        Some(index) => param.ty[..index].to_string(),
+        None => param.ty.clone(),
+    };
+    Param {
+        ty: element_ty,
+        ..param.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{B256, U256};
+
+    fn param(json: serde_json::Value) -> Param {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn eip1967_slot_constants_are_32_bytes() {
+        for slot in [
+            EIP1967_IMPLEMENTATION_SLOT,
+            EIP1967_ADMIN_SLOT,
+            EIP1967_BEACON_SLOT,
+        ] {
+            assert_eq!(
+                FixedBytes::<32>::from_hex(slot).unwrap().len(),
+                32,
+                "{} is not a 32-byte hex slot",
+                slot
+            );
+        }
+    }
+
+    #[test]
+    fn serializes_uint256_array() {
+        let param = param(json!({"name": "values", "type": "uint256[]"}));
+        let value = DynSolValue::Array(vec![
+            DynSolValue::Uint(U256::from(1), 256),
+            DynSolValue::Uint(U256::from(2), 256),
+        ]);
+
+        assert_eq!(serialize_value(param, value).unwrap(), json!(["1", "2"]));
+    }
+
+    #[test]
+    fn serializes_address_array() {
+        let param = param(json!({"name": "owners", "type": "address[]"}));
+        let value = DynSolValue::Array(vec![
+            DynSolValue::Address(Address::ZERO),
+            DynSolValue::Address(Address::ZERO),
+        ]);
+
+        let expected = Address::ZERO.to_string();
+        assert_eq!(
+            serialize_value(param, value).unwrap(),
+            json!([expected, expected])
+        );
+    }
+
+    #[test]
+    fn serializes_array_of_tuples() {
+        let param = param(json!({
+            "name": "entries",
+            "type": "tuple[]",
+            "components": [
+                {"name": "account", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ]
+        }));
+        let value = DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::ZERO),
+            DynSolValue::Uint(U256::from(42), 256),
+        ])]);
+
+        assert_eq!(
+            serialize_value(param, value).unwrap(),
+            json!([{"account": Address::ZERO.to_string(), "amount": "42"}])
+        );
+    }
+
+    #[test]
+    fn serializes_fixed_bytes32_array() {
+        let param = param(json!({"name": "hashes", "type": "bytes32[3]"}));
+        let value = DynSolValue::FixedArray(vec![
+            DynSolValue::FixedBytes(B256::ZERO, 32),
+            DynSolValue::FixedBytes(B256::ZERO, 32),
+            DynSolValue::FixedBytes(B256::ZERO, 32),
+        ]);
+
+        let expected = B256::ZERO.to_string();
+        assert_eq!(
+            serialize_value(param, value).unwrap(),
+            json!([expected, expected, expected])
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::libs::explorer_backend::{ExplorerBackend, VerificationStatus};
+use crate::util::deployment_log::{
+    deployments_file_path, load_deployments_json, write_deployments_json,
+};
+use alloy::primitives::Address;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Explorers can take several minutes to pick up a submission, but a backend stuck reporting
+/// `Pending` shouldn't be able to hang the workflow forever - give up after this many polls
+/// (5s apart, so ~10 minutes total) and surface an error instead.
+const MAX_VERIFICATION_STATUS_POLLS: u32 = 120;
+
+/// Submits standard-JSON-input verification for an already-deployed contract to the configured
+/// explorer backend and polls until it reports success or failure, annotating the matching
+/// `deployments/json/<chainId>.json` record once it does. `contract_artifact_path` is the
+/// `out/<name>.sol/<name>.json` artifact `SelectArtifactScreen` had the user pick, and pins down
+/// exactly which compiled contract to verify - its name alone could be ambiguous if two source
+/// files declare a contract with the same name.
+pub async fn verify_contract(
+    contract_address: Address,
+    contract_artifact_path: PathBuf,
+    chain_id: String,
+    working_dir: PathBuf,
+    explorer_api: Box<dyn ExplorerBackend>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract_name) = artifact_compilation_target(&contract_artifact_path)?;
+    let (standard_json_input, compiler_version) =
+        load_standard_json_input(&working_dir, &contract_artifact_path)?;
+
+    let guid = explorer_api
+        .submit_verification(
+            contract_address,
+            contract_name.clone(),
+            standard_json_input,
+            compiler_version,
+        )
+        .await?;
+
+    let mut explorer_url = None;
+    for _ in 0..MAX_VERIFICATION_STATUS_POLLS {
+        match explorer_api
+            .check_verification_status(guid.clone(), contract_address)
+            .await?
+        {
+            VerificationStatus::Pending => {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            VerificationStatus::Success { explorer_url: url } => {
+                explorer_url = Some(url);
+                break;
+            }
+            VerificationStatus::Failed(reason) => {
+                return Err(format!("Contract verification failed: {}", reason).into())
+            }
+        }
+    }
+    let explorer_url =
+        explorer_url.ok_or("Timed out waiting for the explorer to report a verification result")?;
+
+    mark_contract_verified(
+        &working_dir,
+        &chain_id,
+        &contract_name,
+        contract_address,
+        &explorer_url,
+    )
+}
+
+/// Foundry writes the exact standard-JSON-input it compiled each contract with to
+/// `out/build-info/*.json`, alongside the compiler version - reusing it means the explorer
+/// verifies against bytecode-identical input instead of us reconstructing it from scratch. The
+/// matching build-info entry is found by the artifact's own source path and contract name,
+/// rather than by name alone, so two same-named contracts in different files can't be confused.
+fn load_standard_json_input(
+    working_dir: &PathBuf,
+    contract_artifact_path: &PathBuf,
+) -> Result<(serde_json::Value, String), Box<dyn std::error::Error>> {
+    let (source_path, contract_name) = artifact_compilation_target(contract_artifact_path)?;
+
+    let build_info_dir = working_dir.join("out").join("build-info");
+    let entries = std::fs::read_dir(&build_info_dir).map_err(|_| {
+        format!(
+            "No build-info found at '{}'. Run 'forge build' first",
+            build_info_dir.display()
+        )
+    })?;
+
+    for entry in entries {
+        let path = entry?.path();
+        let build_info: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        let contains_contract = build_info["output"]["contracts"][&source_path]
+            .get(&contract_name)
+            .is_some();
+        if contains_contract {
+            let compiler_version = build_info["solcLongVersion"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            return Ok((build_info["input"].clone(), compiler_version));
+        }
+    }
+
+    Err(format!(
+        "Could not find a build-info artifact containing '{}' ({})",
+        contract_name, source_path
+    )
+    .into())
+}
+
+/// Reads the artifact's own record of which source file and contract name it was compiled from.
+fn artifact_compilation_target(
+    contract_artifact_path: &PathBuf,
+) -> Result<(String, String), Box<dyn std::error::Error>> {
+    let artifact: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(contract_artifact_path)?)?;
+
+    artifact["metadata"]["settings"]["compilationTarget"]
+        .as_object()
+        .and_then(|targets| targets.iter().next())
+        .map(|(source_path, contract_name)| {
+            (
+                source_path.clone(),
+                contract_name.as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .ok_or_else(|| {
+            format!(
+                "Could not read compilation target from artifact '{}'",
+                contract_artifact_path.display()
+            )
+            .into()
+        })
+}
+
+/// Only marks the history record whose `address` matches the contract that was actually
+/// verified - matching on `contract_name` alone would also flag prior redeployments of the same
+/// contract at a different address.
+fn mark_contract_verified(
+    working_dir: &PathBuf,
+    chain_id: &str,
+    contract_name: &str,
+    contract_address: Address,
+    explorer_url: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let deployments_file = deployments_file_path(working_dir, chain_id);
+    if !deployments_file.exists() {
+        return Err(format!(
+            "No deployment log found for chain '{}'. Register the contract before verifying it",
+            chain_id
+        )
+        .into());
+    }
+
+    let contract_address = contract_address.to_string();
+    let mut deployments_json = load_deployments_json(working_dir, chain_id)?;
+    for item in deployments_json["history"].as_array_mut().unwrap() {
+        if let Some(contract) = item["contracts"].get_mut(contract_name) {
+            if contract["address"].as_str() != Some(contract_address.as_str()) {
+                continue;
+            }
+            contract["verified"] = true.into();
+            contract["explorerUrl"] = explorer_url.into();
+        }
+    }
+
+    write_deployments_json(working_dir, chain_id, deployments_json)
+}
@@ -1,6 +1,8 @@
 use crate::errors;
 use crate::screens::register_contract::enter_address::EnterAddressScreen;
 use crate::screens::shared::chain_id::ChainIdScreen;
+use crate::screens::shared::explorer_backend::ExplorerBackendScreen;
+use crate::screens::shared::notification_targets::NotificationTargetsScreen;
 use crate::screens::shared::rpc_url::RpcUrlScreen;
 use crate::state_manager::STATE_MANAGER;
 use crate::workflows::workflow_manager::{process_nested_workflows, Workflow, WorkflowResult};
@@ -57,7 +59,12 @@ impl RegisterContractWorkflow {
         match self.current_screen {
             1 => return WorkflowResult::NextScreen(Box::new(ChainIdScreen::new())),
             2 => return WorkflowResult::NextScreen(Box::new(RpcUrlScreen::new())),
-            3 => return WorkflowResult::NextScreen(Box::new(EnterAddressScreen::new())),
+            // Lets the user pick an explorer backend (or accept the chain's default) so chains
+            // without an Etherscan-compatible API can still be resolved via Blockscout/Sourcify.
+            3 => return WorkflowResult::NextScreen(Box::new(ExplorerBackendScreen::new())),
+            4 => return WorkflowResult::NextScreen(Box::new(EnterAddressScreen::new())),
+            // Optional webhook/Matrix targets to notify once the deployment log is written.
+            5 => return WorkflowResult::NextScreen(Box::new(NotificationTargetsScreen::new())),
             _ => return WorkflowResult::Finished,
         }
     }